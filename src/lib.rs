@@ -1,5 +1,5 @@
 //! # Overview
-//! A library which consists of declarative macros which retry the execution of functions upon failure. Sync and async execution is supported (async via tokio).
+//! A library which consists of declarative macros which retry the execution of functions upon failure. Sync and async execution is supported (async via tokio or async-std; enable exactly one of the `tokio`/`async-std` features).
 
 /// These macros could execute the function more than once. Hence, before each iteration the functiona arguments are cloned/copied to avoid the 'move' compilation error.
 /// Therefore, the function arguments must be binded to identifier/variables.
@@ -44,42 +44,369 @@ impl<T: Debug> Display for RetryError<T> {
 
 impl<T: Debug> Error for RetryError<T> {}
 
+/// The outcome of a single timeout-bounded attempt: either the wrapped function returned an
+/// error, or the attempt did not complete before the configured timeout elapsed.
+#[derive(Debug)]
+pub enum AttemptError<E> {
+    /// The wrapped function returned this error.
+    Inner(E),
+    /// The attempt did not complete within the configured timeout.
+    Timeout,
+}
+
+impl<E: Display> Display for AttemptError<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AttemptError::Inner(e) => write!(f, "{}", e),
+            AttemptError::Timeout => write!(f, "attempt timed out"),
+        }
+    }
+}
+
+impl<E: Error + 'static> Error for AttemptError<E> {}
+
+/// Passed as a leading argument by [`retry_with_ctx!`] / [`retry_async_with_ctx!`] so the
+/// retried function can see its own progress (e.g. to widen a timeout or switch endpoints on
+/// later attempts) instead of retrying blindly.
+#[derive(Debug)]
+pub struct RetryContext<'a, E> {
+    /// The current attempt, 0-indexed.
+    pub attempt: usize,
+    /// Time elapsed since the first attempt started.
+    pub elapsed: std::time::Duration,
+    /// Errors returned by every prior attempt, in order.
+    pub previous_errors: &'a [E],
+}
+
+type RetryIfPredicate<'a, E> = Box<dyn Fn(&E) -> bool + 'a>;
+
+/// The delay applied between attempts by a [`RetryPolicy`].
+enum DelayStrategy {
+    Fixed { delay_ms: u64 },
+    Exponential { base_ms: u64, exponent: f64 },
+}
+
+/// How a [`RetryPolicy`] randomizes the delay it computes before sleeping.
+enum Jitter {
+    /// Sleep for exactly the computed delay.
+    None,
+    /// Sleep for a random duration uniformly picked from `[0, delay]`.
+    Full,
+    /// Sleep for a random duration uniformly picked from `[delay*(1-f), delay*(1+f)]`, matching
+    /// the behavior of [`retry_backoff!`] and [`retry_async_backoff!`].
+    Fraction(f64),
+}
+
+/// A reusable, inspectable retry configuration that complements the declarative macros. Where
+/// the macros each hard-code one strategy (fixed delay, backoff, conditional, ...), a
+/// `RetryPolicy` composes all of them behind a single builder: `RetryPolicy::fixed(ms)` or
+/// `RetryPolicy::exponential(base_ms)`, then any of `.with_max_retries`, `.with_jitter`,
+/// `.with_backoff_exponent`, `.with_max_delay`, `.with_retry_if`.
+///
+/// # Examples
+///
+/// let policy = retry_macro::RetryPolicy::exponential(100)
+///     .with_max_retries(5)
+///     .with_jitter(true)
+///     .with_max_delay(2000);
+/// let result = policy.retry(|| three_arg(var1, var2, var3));
+pub struct RetryPolicy<'a, E: Debug> {
+    strategy: DelayStrategy,
+    max_retries: usize,
+    jitter: Jitter,
+    max_delay_ms: Option<u64>,
+    retry_if: Option<RetryIfPredicate<'a, E>>,
+}
+
+impl<'a, E: Debug> RetryPolicy<'a, E> {
+    /// A policy that sleeps for a fixed `delay_ms` milliseconds between attempts.
+    pub fn fixed(delay_ms: u64) -> Self {
+        Self {
+            strategy: DelayStrategy::Fixed { delay_ms },
+            max_retries: 3,
+            jitter: Jitter::None,
+            max_delay_ms: None,
+            retry_if: None,
+        }
+    }
+
+    /// A policy that grows the delay between attempts as `base_ms * exponent.powi(attempt)`,
+    /// with `exponent` defaulting to `2.0` (override via [`with_backoff_exponent`][Self::with_backoff_exponent]).
+    pub fn exponential(base_ms: u64) -> Self {
+        Self {
+            strategy: DelayStrategy::Exponential {
+                base_ms,
+                exponent: 2.0,
+            },
+            max_retries: 3,
+            jitter: Jitter::None,
+            max_delay_ms: None,
+            retry_if: None,
+        }
+    }
+
+    /// Sets the maximum number of attempts. Defaults to `3`.
+    pub fn with_max_retries(mut self, max_retries: usize) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Enables full jitter: instead of sleeping for the computed delay, sleep for a random
+    /// duration uniformly picked from `[0, delay]`. Defaults to `false`.
+    pub fn with_jitter(mut self, jitter: bool) -> Self {
+        self.jitter = if jitter { Jitter::Full } else { Jitter::None };
+        self
+    }
+
+    /// Enables fractional jitter: instead of sleeping for the computed delay, sleep for a random
+    /// duration uniformly picked from `[delay*(1-fraction), delay*(1+fraction)]`. This is the
+    /// jitter algorithm [`retry_backoff!`] and [`retry_async_backoff!`] use internally; exposed
+    /// here (rather than via `#[macro_export]`) purely so those macros can delegate to
+    /// `RetryPolicy` instead of duplicating the retry loop.
+    #[doc(hidden)]
+    pub fn with_jitter_fraction(mut self, fraction: f64) -> Self {
+        self.jitter = Jitter::Fraction(fraction);
+        self
+    }
+
+    /// Overrides the exponent used by [`RetryPolicy::exponential`]. Has no effect on a
+    /// [`RetryPolicy::fixed`] policy.
+    pub fn with_backoff_exponent(mut self, exponent: f64) -> Self {
+        if let DelayStrategy::Exponential { exponent: e, .. } = &mut self.strategy {
+            *e = exponent;
+        }
+        self
+    }
+
+    /// Caps the computed delay (before jitter is applied) at `max_delay_ms` milliseconds.
+    pub fn with_max_delay(mut self, max_delay_ms: u64) -> Self {
+        self.max_delay_ms = Some(max_delay_ms);
+        self
+    }
+
+    /// Sets a predicate consulted after each failure: if it returns `false` for the error, the
+    /// policy stops retrying and returns immediately instead of consuming the remaining budget.
+    /// The predicate may borrow from its environment; the borrow only needs to outlive the
+    /// policy itself.
+    pub fn with_retry_if(mut self, predicate: impl Fn(&E) -> bool + 'a) -> Self {
+        self.retry_if = Some(Box::new(predicate));
+        self
+    }
+
+    fn delay_for(&self, attempt: u32) -> Option<std::time::Duration> {
+        // Exponential + fractional jitter is exactly what `__retry_backoff_delay_ms` computes for
+        // `retry_backoff!`/`retry_async_backoff!`; delegate instead of re-deriving the formula.
+        if let (DelayStrategy::Exponential { base_ms, exponent }, Jitter::Fraction(fraction)) =
+            (&self.strategy, &self.jitter)
+        {
+            let delay_ms =
+                __retry_backoff_delay_ms(*base_ms, *exponent, *fraction, attempt, self.max_delay_ms);
+            return if delay_ms == 0 {
+                None
+            } else {
+                Some(std::time::Duration::from_millis(delay_ms))
+            };
+        }
+
+        let raw_ms = match self.strategy {
+            DelayStrategy::Fixed { delay_ms } => delay_ms as f64,
+            DelayStrategy::Exponential { base_ms, exponent } => {
+                base_ms as f64 * exponent.powi(attempt as i32)
+            }
+        };
+        let capped_ms = match self.max_delay_ms {
+            Some(max) => raw_ms.min(max as f64),
+            None => raw_ms,
+        };
+        let delay_ms = match self.jitter {
+            Jitter::None => capped_ms,
+            Jitter::Full => capped_ms * __retry_jitter_unit(),
+            Jitter::Fraction(fraction) => {
+                let lower = capped_ms * (1.0 - fraction);
+                let upper = capped_ms * (1.0 + fraction);
+                lower + (upper - lower) * __retry_jitter_unit()
+            }
+        };
+        if delay_ms <= 0.0 {
+            None
+        } else {
+            Some(std::time::Duration::from_millis(delay_ms as u64))
+        }
+    }
+
+    /// Runs `f` until it succeeds or the attempt budget is exhausted, sleeping after each failed
+    /// attempt (the last one included) per the configured strategy and honoring
+    /// [`with_retry_if`][Self::with_retry_if].
+    pub fn retry<F, T>(&self, mut f: F) -> Result<T, RetryError<E>>
+    where
+        F: FnMut() -> Result<T, E>,
+    {
+        let mut errs = Vec::with_capacity(self.max_retries);
+        for attempt in 0..self.max_retries {
+            match f() {
+                Ok(res) => return Ok(res),
+                Err(e) => {
+                    let should_retry = self.retry_if.as_ref().is_none_or(|p| p(&e));
+                    errs.push(e);
+                    if !should_retry {
+                        return Err(RetryError { retries: errs });
+                    }
+                    if let Some(delay) = self.delay_for(attempt as u32) {
+                        std::thread::sleep(delay);
+                    }
+                }
+            }
+        }
+        Err(RetryError { retries: errs })
+    }
+
+    /// The async counterpart of [`retry`][Self::retry]. Requires the `tokio` or `async-std`
+    /// feature so the policy knows which runtime to sleep on between attempts.
+    #[cfg(any(feature = "tokio", feature = "async-std"))]
+    pub async fn retry_async<F, Fut, T>(&self, mut f: F) -> Result<T, RetryError<E>>
+    where
+        F: FnMut() -> Fut,
+        Fut: std::future::Future<Output = Result<T, E>>,
+    {
+        let mut errs = Vec::with_capacity(self.max_retries);
+        for attempt in 0..self.max_retries {
+            match f().await {
+                Ok(res) => return Ok(res),
+                Err(e) => {
+                    let should_retry = self.retry_if.as_ref().is_none_or(|p| p(&e));
+                    errs.push(e);
+                    if !should_retry {
+                        return Err(RetryError { retries: errs });
+                    }
+                    if let Some(delay) = self.delay_for(attempt as u32) {
+                        let delay_ms = delay.as_millis() as u64;
+                        crate::__retry_async_sleep!(delay_ms);
+                    }
+                }
+            }
+        }
+        Err(RetryError { retries: errs })
+    }
+}
+
 /// Retry synchronous function without sleep in between retries. Arguments are: number of retries, function, function arguments.
 #[macro_export]
 macro_rules! retry {
+    ($retries: expr, $f: expr, $($params:tt)* ) => {
+        $crate::RetryPolicy::fixed(0)
+            .with_max_retries($retries)
+            .retry(move || {
+                shadow_clone::shadow_clone!($($params)*);
+                $f($($params)*)
+            })
+    };
+}
+
+/// Retry synchronous function without sleep in between retries, short-circuiting on errors the
+/// predicate deems non-retryable. Arguments are: number of retries, predicate (`|&E| -> bool`),
+/// function, function arguments. The failing error is always pushed onto `RetryError::retries`,
+/// whatever the predicate returns; if the predicate returns `false` the accumulated errors are
+/// returned immediately instead of exhausting the remaining attempts.
+#[macro_export]
+macro_rules! retry_if {
+    ($retries: expr, $predicate: expr, $f: expr, $($params:tt)* ) => {
+        $crate::RetryPolicy::fixed(0)
+            .with_max_retries($retries)
+            .with_retry_if($predicate)
+            .retry(move || {
+                shadow_clone::shadow_clone!($($params)*);
+                $f($($params)*)
+            })
+    };
+}
+
+/// Retry synchronous function with sleep in between retries. Arguments are: number of retries, sleep time (milliseconds), function, function arguments.
+#[macro_export]
+macro_rules! retry_sleep {
+    ($retries: expr, $time_ms: expr, $f: expr, $($params:tt)* ) => {
+        $crate::RetryPolicy::fixed($time_ms)
+            .with_max_retries($retries)
+            .retry(move || {
+                shadow_clone::shadow_clone!($($params)*);
+                $f($($params)*)
+            })
+    };
+}
+
+/// Retry asynchronous function without sleep in between retries. Arguments are: number of retries, function, function arguments.
+#[macro_export]
+macro_rules! retry_async {
     ($retries: expr, $f: expr, $($params:tt)* ) => {
         {
-            (|| {
+            let r = (async {
             let mut errs = Vec::with_capacity($retries);
             for _ in 0..$retries {
                 shadow_clone::shadow_clone!($($params)*);
-                match $f($($params)*) {
+                match $f($($params)*).await {
                     Ok(res) => return Ok(res),
                     Err(e) => {
                         errs.push(e);
                     }
                 }
             }
-            Err(RetryError{retries: errs})
-            })()
+            Err(RetryError {retries: errs})
+            }).await;
+            r
         }
     };
 }
 
-/// Retry synchronous function with sleep in between retries. Arguments are: number of retries, sleep time (milliseconds), function, function arguments.
+/// Retry asynchronous function without sleep in between retries, short-circuiting on errors the
+/// predicate deems non-retryable. Arguments are: number of retries, predicate (`|&E| -> bool`),
+/// function, function arguments. See [`retry_if`] for the short-circuiting semantics.
 #[macro_export]
-macro_rules! retry_sleep {
-    ($retries: expr, $time_ms: expr, $f: expr, $($params:tt)* ) => {
+macro_rules! retry_async_if {
+    ($retries: expr, $predicate: expr, $f: expr, $($params:tt)* ) => {
         {
-            (|| {
+            let r = (async {
             let mut errs = Vec::with_capacity($retries);
             for _ in 0..$retries {
                 shadow_clone::shadow_clone!($($params)*);
-                match $f($($params)*) {
+                match $f($($params)*).await {
+                    Ok(res) => return Ok(res),
+                    Err(e) => {
+                        let should_retry = $predicate(&e);
+                        errs.push(e);
+                        if !should_retry {
+                            return Err(RetryError{retries: errs});
+                        }
+                    }
+                }
+            }
+            Err(RetryError {retries: errs})
+            }).await;
+            r
+        }
+    };
+}
+
+/// Retry synchronous function without sleep in between retries, invoking it with a leading
+/// [`RetryContext`] argument so it can see its own attempt number, elapsed time, and previous
+/// errors. Arguments are: number of retries, function, function arguments. `$f` must accept the
+/// context as its first parameter, e.g. `fn f(ctx: RetryContext<TestError>, arg1: i32) -> ...`.
+#[macro_export]
+macro_rules! retry_with_ctx {
+    ($retries: expr, $f: expr, $($params:tt)* ) => {
+        {
+            (|| {
+            let start_time = std::time::Instant::now();
+            let mut errs = Vec::with_capacity($retries);
+            for attempt in 0..$retries {
+                shadow_clone::shadow_clone!($($params)*);
+                let ctx = $crate::RetryContext {
+                    attempt,
+                    elapsed: start_time.elapsed(),
+                    previous_errors: &errs,
+                };
+                match $f(ctx, $($params)*) {
                     Ok(res) => return Ok(res),
                     Err(e) => {
                         errs.push(e);
-                        std::thread::sleep(std::time::Duration::from_millis($time_ms))
                     }
                 }
             }
@@ -89,16 +416,23 @@ macro_rules! retry_sleep {
     };
 }
 
-/// Retry asynchronous function without sleep in between retries. Arguments are: number of retries, function, function arguments.
+/// Retry asynchronous function without sleep in between retries, invoking it with a leading
+/// [`RetryContext`] argument. See [`retry_with_ctx`] for the context's semantics.
 #[macro_export]
-macro_rules! retry_async {
+macro_rules! retry_async_with_ctx {
     ($retries: expr, $f: expr, $($params:tt)* ) => {
         {
             let r = (async {
+            let start_time = std::time::Instant::now();
             let mut errs = Vec::with_capacity($retries);
-            for _ in 0..$retries {
+            for attempt in 0..$retries {
                 shadow_clone::shadow_clone!($($params)*);
-                match $f($($params)*).await {
+                let ctx = $crate::RetryContext {
+                    attempt,
+                    elapsed: start_time.elapsed(),
+                    previous_errors: &errs,
+                };
+                match $f(ctx, $($params)*).await {
                     Ok(res) => return Ok(res),
                     Err(e) => {
                         errs.push(e);
@@ -112,21 +446,67 @@ macro_rules! retry_async {
     };
 }
 
-/// Retry asynchronous function with sleep (enable feature tokio) in between retries. Arguments are: number of retries, sleep time (milliseconds), function, function arguments.
+/// Sleeps for `$time_ms` milliseconds on whichever async runtime feature is enabled. Exactly one
+/// of `tokio`/`async-std` must be enabled for this to compile; this is what lets
+/// [`retry_async_sleep`] and [`retry_async_backoff`] stay runtime-agnostic, matching how
+/// `retry_fn` ships both tokio and async-std variants.
+#[doc(hidden)]
 #[macro_export]
-#[cfg(feature = "tokio")]
+macro_rules! __retry_async_sleep {
+    ($time_ms: expr) => {{
+        #[cfg(all(feature = "tokio", feature = "async-std"))]
+        compile_error!(
+            "retry_macro: enable exactly one of the `tokio` or `async-std` features, not both"
+        );
+        #[cfg(not(any(feature = "tokio", feature = "async-std")))]
+        compile_error!(
+            "retry_macro: enable the `tokio` or `async-std` feature to use sleep-based async retry macros"
+        );
+        #[cfg(feature = "tokio")]
+        tokio::time::sleep(tokio::time::Duration::from_millis($time_ms)).await;
+        #[cfg(feature = "async-std")]
+        async_std::task::sleep(std::time::Duration::from_millis($time_ms)).await;
+    }};
+}
+
+/// Retry asynchronous function with sleep (enable feature `tokio` or `async-std`) in between
+/// retries. Arguments are: number of retries, sleep time (milliseconds), function, function
+/// arguments.
+#[macro_export]
+#[cfg(any(feature = "tokio", feature = "async-std"))]
 macro_rules! retry_async_sleep {
     ($retries: expr, $time_ms: expr, $f: expr, $($params:tt)* ) => {
+        $crate::RetryPolicy::fixed($time_ms)
+            .with_max_retries($retries)
+            .retry_async(move || {
+                shadow_clone::shadow_clone!($($params)*);
+                $f($($params)*)
+            })
+            .await
+    };
+}
+
+/// Retry asynchronous function (enable feature tokio), bounding each individual attempt with a
+/// timeout. Arguments are: number of retries, timeout (milliseconds), function, function
+/// arguments. A timed-out attempt is recorded as [`AttemptError::Timeout`] rather than
+/// [`AttemptError::Inner`], so callers can tell logic errors and timeouts apart in
+/// `RetryError<AttemptError<E>>::retries` once all attempts are exhausted.
+#[macro_export]
+#[cfg(feature = "tokio")]
+macro_rules! retry_async_timeout {
+    ($retries: expr, $timeout_ms: expr, $f: expr, $($params:tt)* ) => {
         {
             let r = (async {
-            let mut errs = Vec::with_capacity($retries);
+            let mut errs: Vec<$crate::AttemptError<_>> = Vec::with_capacity($retries);
             for _ in 0..$retries {
                 shadow_clone::shadow_clone!($($params)*);
-                match $f($($params)*).await {
-                    Ok(res) => return Ok(res),
-                    Err(e) => {
-                        errs.push(e);
-                        tokio::time::sleep(tokio::time::Duration::from_millis($time_ms)).await;
+                match tokio::time::timeout(tokio::time::Duration::from_millis($timeout_ms), $f($($params)*)).await {
+                    Ok(Ok(res)) => return Ok(res),
+                    Ok(Err(e)) => {
+                        errs.push($crate::AttemptError::Inner(e));
+                    }
+                    Err(_elapsed) => {
+                        errs.push($crate::AttemptError::Timeout);
                     }
                 }
             }
@@ -136,6 +516,115 @@ macro_rules! retry_async_sleep {
         }
     };
 }
+
+/// Returns a uniform random number in `[0, 1)`. Backed by a tiny xorshift64 generator seeded
+/// from the system clock so the crate does not need to pull in a full RNG dependency just for
+/// jitter.
+#[doc(hidden)]
+pub fn __retry_jitter_unit() -> f64 {
+    use std::sync::atomic::{AtomicU64, Ordering};
+    static STATE: AtomicU64 = AtomicU64::new(0x9E3779B97F4A7C15);
+
+    let seed = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_nanos() as u64)
+        .unwrap_or(0xDEAD_BEEF_u64);
+    let mut x = STATE.fetch_add(0x9E3779B97F4A7C15, Ordering::Relaxed) ^ seed;
+    x ^= x << 13;
+    x ^= x >> 7;
+    x ^= x << 17;
+    STATE.store(x, Ordering::Relaxed);
+    (x >> 11) as f64 / (1u64 << 53) as f64
+}
+
+/// Computes the delay (in milliseconds) for attempt `n` (0-indexed) of an exponential backoff
+/// with jitter: `delay = base_ms * multiplier.powi(n)`, optionally clamped to `max_delay_ms`,
+/// then a value is picked uniformly from `[delay*(1-jitter), delay*(1+jitter)]`.
+#[doc(hidden)]
+pub fn __retry_backoff_delay_ms(
+    base_ms: u64,
+    multiplier: f64,
+    jitter: f64,
+    attempt: u32,
+    max_delay_ms: Option<u64>,
+) -> u64 {
+    let raw = base_ms as f64 * multiplier.powi(attempt as i32);
+    let capped = match max_delay_ms {
+        Some(max) => raw.min(max as f64),
+        None => raw,
+    };
+    let lower = capped * (1.0 - jitter);
+    let upper = capped * (1.0 + jitter);
+    let delay = lower + (upper - lower) * __retry_jitter_unit();
+    delay.max(0.0) as u64
+}
+
+/// Retry synchronous function with exponential backoff and jitter in between retries. Arguments
+/// are: number of retries, base delay (milliseconds), multiplier, jitter fraction (`0.0..=1.0`),
+/// function, function arguments. Optionally a max delay (milliseconds) can be given right before
+/// the function via `max_delay = <expr>` to cap the computed delay before jitter is applied.
+///
+/// # Examples
+///
+/// let result = retry_macro::retry_backoff!(5, 100, 2.0, 0.2, three_arg, var1, var2, var3);
+/// let result = retry_macro::retry_backoff!(5, 100, 2.0, 0.2, max_delay = 2000, three_arg, var1, var2, var3);
+#[macro_export]
+macro_rules! retry_backoff {
+    ($retries: expr, $base_ms: expr, $multiplier: expr, $jitter: expr, max_delay = $max_delay_ms: expr, $f: expr, $($params:tt)* ) => {
+        $crate::RetryPolicy::exponential($base_ms)
+            .with_max_retries($retries)
+            .with_backoff_exponent($multiplier)
+            .with_jitter_fraction($jitter)
+            .with_max_delay($max_delay_ms)
+            .retry(move || {
+                shadow_clone::shadow_clone!($($params)*);
+                $f($($params)*)
+            })
+    };
+    ($retries: expr, $base_ms: expr, $multiplier: expr, $jitter: expr, $f: expr, $($params:tt)* ) => {
+        $crate::RetryPolicy::exponential($base_ms)
+            .with_max_retries($retries)
+            .with_backoff_exponent($multiplier)
+            .with_jitter_fraction($jitter)
+            .retry(move || {
+                shadow_clone::shadow_clone!($($params)*);
+                $f($($params)*)
+            })
+    };
+}
+
+/// Retry asynchronous function (enable feature `tokio` or `async-std`) with exponential backoff
+/// and jitter in between retries. Arguments are: number of retries, base delay (milliseconds),
+/// multiplier, jitter fraction (`0.0..=1.0`), function, function arguments. Optionally a max
+/// delay (milliseconds) can be given right before the function via `max_delay = <expr>`.
+#[macro_export]
+#[cfg(any(feature = "tokio", feature = "async-std"))]
+macro_rules! retry_async_backoff {
+    ($retries: expr, $base_ms: expr, $multiplier: expr, $jitter: expr, max_delay = $max_delay_ms: expr, $f: expr, $($params:tt)* ) => {
+        $crate::RetryPolicy::exponential($base_ms)
+            .with_max_retries($retries)
+            .with_backoff_exponent($multiplier)
+            .with_jitter_fraction($jitter)
+            .with_max_delay($max_delay_ms)
+            .retry_async(move || {
+                shadow_clone::shadow_clone!($($params)*);
+                $f($($params)*)
+            })
+            .await
+    };
+    ($retries: expr, $base_ms: expr, $multiplier: expr, $jitter: expr, $f: expr, $($params:tt)* ) => {
+        $crate::RetryPolicy::exponential($base_ms)
+            .with_max_retries($retries)
+            .with_backoff_exponent($multiplier)
+            .with_jitter_fraction($jitter)
+            .retry_async(move || {
+                shadow_clone::shadow_clone!($($params)*);
+                $f($($params)*)
+            })
+            .await
+    };
+}
+
 #[cfg(test)]
 mod tests {
     use std::{error::Error, fmt::Display, time::Instant, vec};
@@ -169,6 +658,14 @@ mod tests {
         Err(TestError)
     }
 
+    fn escalating_function(ctx: RetryContext<'_, TestError>, _arg1: i32) -> Result<i32, TestError> {
+        if ctx.attempt < 2 {
+            Err(TestError)
+        } else {
+            Ok(ctx.previous_errors.len() as i32)
+        }
+    }
+
     #[derive(Debug, Clone)]
     struct SomeObject {
         _v: Vec<i32>,
@@ -265,4 +762,259 @@ mod tests {
         assert!(actual.is_err());
         assert_eq!(actual.unwrap_err().retries.len(), 2);
     }
+
+    #[test]
+    fn test_backoff_delay_ms_grows_and_clamps() {
+        let d0 = __retry_backoff_delay_ms(100, 2.0, 0.0, 0, None);
+        let d1 = __retry_backoff_delay_ms(100, 2.0, 0.0, 1, None);
+        let d2 = __retry_backoff_delay_ms(100, 2.0, 0.0, 2, None);
+        assert_eq!(d0, 100);
+        assert_eq!(d1, 200);
+        assert_eq!(d2, 400);
+
+        let capped = __retry_backoff_delay_ms(100, 2.0, 0.0, 5, Some(250));
+        assert_eq!(capped, 250);
+    }
+
+    #[test]
+    fn test_backoff_delay_ms_jitter_within_bounds() {
+        for _ in 0..50 {
+            let delay = __retry_backoff_delay_ms(100, 2.0, 0.5, 2, None);
+            assert!((200..=600).contains(&delay));
+        }
+    }
+
+    #[test]
+    fn test_fail_function_w_backoff() {
+        let v = vec![1, 2, 3];
+        let start_time = Instant::now();
+        let actual = retry_backoff!(3, 50, 2.0, 0.0, one_arg_vec, v);
+        let elapsed = start_time.elapsed().as_millis();
+        assert!(elapsed >= 50 + 100 + 200);
+        assert!(actual.is_err());
+        assert_eq!(actual.unwrap_err().retries.len(), 3);
+    }
+
+    #[test]
+    fn test_fail_function_w_backoff_max_delay() {
+        let v = vec![1, 2, 3];
+        let start_time = Instant::now();
+        let actual = retry_backoff!(3, 50, 2.0, 0.0, max_delay = 60, one_arg_vec, v);
+        let elapsed = start_time.elapsed().as_millis();
+        assert!(elapsed >= 50 + 60 + 60);
+        assert!(actual.is_err());
+        assert_eq!(actual.unwrap_err().retries.len(), 3);
+    }
+
+    #[test]
+    fn test_fail_function_retry_if_short_circuits() {
+        let var1 = 1;
+        let var2 = 2;
+        let actual = retry_if!(5, |_e: &TestError| false, failing_function, var1, var2);
+        assert!(actual.is_err());
+        assert_eq!(actual.unwrap_err().retries.len(), 1);
+    }
+
+    #[test]
+    fn test_fail_function_retry_if_borrows_local_state() {
+        let allow_retry = false;
+        let var1 = 1;
+        let var2 = 2;
+        let actual = retry_if!(5, |_e: &TestError| allow_retry, failing_function, var1, var2);
+        assert!(actual.is_err());
+        assert_eq!(actual.unwrap_err().retries.len(), 1);
+    }
+
+    #[test]
+    fn test_fail_function_retry_if_keeps_retrying() {
+        let var1 = 1;
+        let var2 = 2;
+        let actual = retry_if!(3, |_e: &TestError| true, failing_function, var1, var2);
+        assert!(actual.is_err());
+        assert_eq!(actual.unwrap_err().retries.len(), 3);
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn test_fail_function_async_retry_if_short_circuits() {
+        let var1 = 1;
+        let var2 = 2;
+        let actual =
+            retry_async_if!(5, |_e: &TestError| false, failing_function_async, var1, var2);
+        assert!(actual.is_err());
+        assert_eq!(actual.unwrap_err().retries.len(), 1);
+    }
+
+    #[cfg(feature = "tokio")]
+    async fn slow_function_async(_arg1: i32) -> Result<i32, TestError> {
+        tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+        Err(TestError)
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn test_fail_function_async_w_timeout() {
+        let var1 = 1;
+        let actual = retry_async_timeout!(3, 10, slow_function_async, var1);
+        assert!(actual.is_err());
+        let retries = actual.unwrap_err().retries;
+        assert_eq!(retries.len(), 3);
+        assert!(retries
+            .iter()
+            .all(|e| matches!(e, AttemptError::Timeout)));
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn test_fail_function_async_w_timeout_records_inner_error() {
+        let var1 = 1;
+        let var2 = 2;
+        let actual = retry_async_timeout!(2, 1000, failing_function_async, var1, var2);
+        assert!(actual.is_err());
+        let retries = actual.unwrap_err().retries;
+        assert_eq!(retries.len(), 2);
+        assert!(retries
+            .iter()
+            .all(|e| matches!(e, AttemptError::Inner(TestError))));
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn test_fail_function_async_w_backoff() {
+        let var1 = 1;
+        let var2 = 2;
+        let start_time = Instant::now();
+        let actual = retry_async_backoff!(3, 50, 2.0, 0.0, failing_function_async, var1, var2);
+        let elapsed = start_time.elapsed().as_millis();
+        assert!(elapsed >= 50 + 100 + 200);
+        assert!(actual.is_err());
+        assert_eq!(actual.unwrap_err().retries.len(), 3);
+    }
+
+    #[cfg(feature = "async-std")]
+    async fn failing_function_async_std(_arg1: i32, _arg2: i32) -> Result<i32, TestError> {
+        async_std::task::sleep(std::time::Duration::from_millis(1)).await;
+        Err(TestError)
+    }
+
+    #[cfg(feature = "async-std")]
+    #[async_std::test]
+    async fn test_fail_function_async_std_w_sleep() {
+        let var1 = 1;
+        let var2 = 2;
+        let start_time = Instant::now();
+        let actual = retry_async_sleep!(2, 100, failing_function_async_std, var1, var2);
+        let elapsed = start_time.elapsed().as_millis();
+        assert!(elapsed >= 200);
+        assert!(actual.is_err());
+        assert_eq!(actual.unwrap_err().retries.len(), 2);
+    }
+
+    #[cfg(feature = "async-std")]
+    #[async_std::test]
+    async fn test_fail_function_async_std_w_backoff() {
+        let var1 = 1;
+        let var2 = 2;
+        let start_time = Instant::now();
+        let actual =
+            retry_async_backoff!(3, 50, 2.0, 0.0, failing_function_async_std, var1, var2);
+        let elapsed = start_time.elapsed().as_millis();
+        assert!(elapsed >= 50 + 100 + 200);
+        assert!(actual.is_err());
+        assert_eq!(actual.unwrap_err().retries.len(), 3);
+    }
+
+    #[test]
+    fn test_retry_policy_fixed() {
+        let v = vec![1, 2, 3];
+        let start_time = Instant::now();
+        let policy = RetryPolicy::fixed(50).with_max_retries(3);
+        let actual = policy.retry(|| one_arg_vec(v.clone()));
+        let elapsed = start_time.elapsed().as_millis();
+        assert!(elapsed >= 150);
+        assert!(actual.is_err());
+        assert_eq!(actual.unwrap_err().retries.len(), 3);
+    }
+
+    #[test]
+    fn test_retry_policy_exponential_max_delay() {
+        let start_time = Instant::now();
+        let policy = RetryPolicy::exponential(50)
+            .with_max_retries(3)
+            .with_max_delay(60);
+        let actual = policy.retry(|| failing_function(1, 2));
+        let elapsed = start_time.elapsed().as_millis();
+        assert!(elapsed >= 50 + 60 + 60);
+        assert!(actual.is_err());
+        assert_eq!(actual.unwrap_err().retries.len(), 3);
+    }
+
+    #[test]
+    fn test_retry_policy_retry_if_short_circuits() {
+        let policy = RetryPolicy::fixed(0)
+            .with_max_retries(5)
+            .with_retry_if(|_e: &TestError| false);
+        let actual = policy.retry(|| failing_function(1, 2));
+        assert!(actual.is_err());
+        assert_eq!(actual.unwrap_err().retries.len(), 1);
+    }
+
+    #[test]
+    fn test_retry_policy_retry_if_borrows_local_state() {
+        let allow_retry = false;
+        let policy = RetryPolicy::fixed(0)
+            .with_max_retries(5)
+            .with_retry_if(|_e: &TestError| allow_retry);
+        let actual = policy.retry(|| failing_function(1, 2));
+        assert!(actual.is_err());
+        assert_eq!(actual.unwrap_err().retries.len(), 1);
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn test_retry_policy_retry_async() {
+        let policy = RetryPolicy::fixed(10).with_max_retries(3);
+        let actual = policy.retry_async(|| failing_function_async(1, 2)).await;
+        assert!(actual.is_err());
+        assert_eq!(actual.unwrap_err().retries.len(), 3);
+    }
+
+    #[test]
+    fn test_retry_with_ctx_escalates_and_succeeds() {
+        let var1 = 1;
+        let actual = retry_with_ctx!(5, escalating_function, var1);
+        assert!(actual.is_ok());
+        assert_eq!(actual.unwrap(), 2);
+    }
+
+    #[test]
+    fn test_retry_with_ctx_exhausts_attempts() {
+        fn always_fails(ctx: RetryContext<'_, TestError>, _arg1: i32) -> Result<i32, TestError> {
+            let _ = ctx;
+            Err(TestError)
+        }
+        let var1 = 1;
+        let actual = retry_with_ctx!(3, always_fails, var1);
+        assert!(actual.is_err());
+        assert_eq!(actual.unwrap_err().retries.len(), 3);
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn test_retry_async_with_ctx_escalates_and_succeeds() {
+        async fn escalating_function_async(
+            ctx: RetryContext<'_, TestError>,
+            _arg1: i32,
+        ) -> Result<i32, TestError> {
+            if ctx.attempt < 1 {
+                Err(TestError)
+            } else {
+                Ok(ctx.previous_errors.len() as i32)
+            }
+        }
+        let var1 = 1;
+        let actual = retry_async_with_ctx!(3, escalating_function_async, var1);
+        assert!(actual.is_ok());
+        assert_eq!(actual.unwrap(), 1);
+    }
 }